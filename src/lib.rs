@@ -1,35 +1,47 @@
 use std::collections::HashMap;
+use std::hash::Hash;
 
 /// A node in a Trie data structure.
 ///
-/// Each node stores a map of child nodes (`children`) indexed by characters,
-/// and a boolean flag (`is_end_of_word`) indicating whether the node represents the end of a valid word.
+/// Each node stores a map of child nodes (`children`) indexed by a key symbol `K`, an
+/// optional value `V`, and a `word_count` that is nonzero exactly when the node marks the
+/// end of a stored entry (and counts how many times it was inserted).
 #[derive(Debug)]
-pub struct TrieNode {
-    children: HashMap<char, Box<TrieNode>>,
-    is_end_of_word: bool,
+pub struct TrieNode<K: Eq + Hash + Clone, V> {
+    children: HashMap<K, Box<TrieNode<K, V>>>,
+    value: Option<V>,
+    word_count: u32,
 }
 
-/// A Trie (prefix tree) data structure for efficient string prefix matching.
+/// A Trie (prefix tree) data structure for efficient prefix matching and key-value lookup.
 ///
-/// A Trie stores a set of strings in a tree structure, where each node represents a character in a string,
-/// and paths from the root to nodes marked as `is_end_of_word` represent complete words.
+/// A Trie stores a set of key sequences in a tree structure, where each node represents a
+/// symbol `K`, and paths from the root to nodes carrying a `value` represent complete entries.
+/// `K` defaults to `char` and `V` to `()`, so `Trie` behaves like the original string set
+/// unless a different key or value type is chosen.
 #[derive(Debug)]
-pub struct Trie {
-    root: TrieNode,
+pub struct Trie<K: Eq + Hash + Clone = char, V = ()> {
+    root: TrieNode<K, V>,
 }
 
-impl TrieNode {
-    /// Creates a new `TrieNode` with an empty `children` map and `is_end_of_word` set to `false`.
+impl<K: Eq + Hash + Clone, V> TrieNode<K, V> {
+    /// Creates a new `TrieNode` with an empty `children` map, no value, and a zero `word_count`.
     pub fn new() -> Self {
         Self {
             children: HashMap::new(),
-            is_end_of_word: false,
+            value: None,
+            word_count: 0,
         }
     }
 }
 
-impl Trie {
+impl<K: Eq + Hash + Clone, V> Default for TrieNode<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Eq + Hash + Clone, V> Trie<K, V> {
     /// Creates a new, empty `Trie` with a root node.
     pub fn new() -> Self {
         Self {
@@ -37,52 +49,236 @@ impl Trie {
         }
     }
 
-    /// Inserts a word into the `Trie`.
+    /// Inserts a key sequence into the `Trie`, associating it with `value`.
     ///
-    /// This method iterates over the characters of the word, creating new nodes or following existing ones as needed.
-    /// The `is_end_of_word` flag is set to `true` on the last node to mark the end of the inserted word.
-    pub fn insert(&mut self, word: &str) {
+    /// This method iterates over `keys`, creating new nodes or following existing ones as
+    /// needed. `value` is stored on the last node and its `word_count` is incremented, so
+    /// inserting the same sequence again is tracked as a repeat occurrence.
+    pub fn insert(&mut self, keys: impl IntoIterator<Item = K>, value: V) {
         let mut node = &mut self.root;
-        for char in word.chars() {
-            node = node.children.entry(char).or_insert(Box::new(TrieNode::new()));
+        for key in keys {
+            node = node.children.entry(key).or_insert_with(|| Box::new(TrieNode::new()));
         }
-        node.is_end_of_word = true;
+        node.value = Some(value);
+        node.word_count += 1;
     }
 
-    /// A private helper method for searching for a word or prefix in the Trie.
-    ///
-    /// Returns `true` if the word or prefix is found, `false` otherwise.
-    fn search(&mut self, word: &str, prefix: bool) -> bool {
+    /// A private helper for walking `keys` down from the root, returning the node reached.
+    fn walk(&self, keys: impl IntoIterator<Item = K>) -> Option<&TrieNode<K, V>> {
         let mut node = &self.root;
-        for char in word.chars() {
-            if let Some(next_node) = node.children.get(&char) {
-                node = next_node;
-            } else {
-                return false;
-            }
-        }
-        if prefix {
-            return true; // If searching for a prefix, any match is sufficient
-        } else {
-            node.is_end_of_word // If searching for a full word, check if we're at the end of a word
+        for key in keys {
+            node = node.children.get(&key)?;
         }
+        Some(node)
     }
 
     /// Searches for a prefix in the `Trie`.
     ///
     /// Returns `true` if the prefix exists in the `Trie`, `false` otherwise.
-    pub fn search_prefix(&mut self, word: &str) -> bool {
-        self.search(word, true)
+    pub fn search_prefix(&self, keys: impl IntoIterator<Item = K>) -> bool {
+        self.walk(keys).is_some()
+    }
+
+    /// Searches for a full entry in the `Trie`.
+    ///
+    /// Returns the stored value if `keys` names a complete entry, `None` otherwise.
+    pub fn get(&self, keys: impl IntoIterator<Item = K>) -> Option<&V> {
+        let node = self.walk(keys)?;
+        if node.word_count > 0 {
+            node.value.as_ref()
+        } else {
+            None
+        }
+    }
+
+    /// Returns how many times the entry for `keys` was inserted, or `0` if it is absent.
+    ///
+    /// `search_full_world` on `Trie<char, ()>` is defined in terms of this: presence is just
+    /// `count(word) > 0`.
+    pub fn count(&self, keys: impl IntoIterator<Item = K>) -> u32 {
+        self.walk(keys).map_or(0, |node| node.word_count)
+    }
+
+    /// Removes the entry for `keys` from the `Trie`, pruning any branches left dead by its
+    /// removal.
+    ///
+    /// Returns `false` if `keys` was never present.
+    pub fn remove(&mut self, keys: impl IntoIterator<Item = K>) -> bool {
+        let keys: Vec<K> = keys.into_iter().collect();
+        Self::remove_from(&mut self.root, &keys)
+    }
+
+    /// Recursively descends `node` following `keys`, clearing the value and `word_count` on
+    /// the terminal node. On the way back up, a child is dropped from its parent's
+    /// `children` map once it has no children of its own and no longer marks a word's end.
+    fn remove_from(node: &mut TrieNode<K, V>, keys: &[K]) -> bool {
+        match keys.split_first() {
+            None => {
+                if node.word_count == 0 {
+                    return false;
+                }
+                node.value = None;
+                node.word_count = 0;
+                true
+            }
+            Some((key, rest)) => match node.children.get_mut(key) {
+                Some(child) => {
+                    let removed = Self::remove_from(child, rest);
+                    if removed && child.children.is_empty() && child.word_count == 0 {
+                        node.children.remove(key);
+                    }
+                    removed
+                }
+                None => false,
+            },
+        }
+    }
+}
+
+impl<K: Eq + Hash + Clone, V> Default for Trie<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Trie<char, ()> {
+    /// Inserts `word` into the `Trie`, one `char` per node.
+    ///
+    /// A back-compat wrapper over the generic `insert` for the original string-keyed,
+    /// value-less `Trie<char, ()>` so callers that only ever stored words don't need to
+    /// spell out `.chars()` and `()` at every call site.
+    pub fn insert_word(&mut self, word: &str) {
+        self.insert(word.chars(), ());
     }
 
     /// Searches for a full word in the `Trie`.
     ///
-    /// Returns `true` if the exact word exists in the `Trie`, `false` otherwise.
-    pub fn search_full_world(&mut self, word: &str) -> bool {
-        self.search(word, false)
+    /// Returns `true` if the exact word exists in the `Trie`, `false` otherwise. A back-compat
+    /// wrapper over `count`, kept for callers of the original string-keyed `Trie`.
+    pub fn search_full_world(&self, word: &str) -> bool {
+        self.count(word.chars()) > 0
     }
 }
 
+impl<V> Trie<char, V> {
+    /// Collects every complete word stored under the given `prefix`.
+    ///
+    /// Walks down to the node at the end of `prefix` (the same traversal `walk` uses),
+    /// then runs a DFS over that subtree, returning each word whose node carries a value.
+    pub fn collect_words(&self, prefix: &str) -> Vec<String> {
+        let node = match self.walk(prefix.chars()) {
+            Some(node) => node,
+            None => return Vec::new(),
+        };
+
+        let mut counted = Vec::new();
+        let mut buffer = String::new();
+        Self::collect_counted(node, &mut buffer, &mut counted);
+        counted
+            .into_iter()
+            .map(|(suffix, _)| format!("{prefix}{suffix}"))
+            .collect()
+    }
+
+    /// Returns up to `limit` words stored under `prefix`, suitable for autocomplete suggestions.
+    pub fn autocomplete(&self, prefix: &str, limit: usize) -> Vec<String> {
+        let mut words = self.collect_words(prefix);
+        words.truncate(limit);
+        words
+    }
+
+    /// Returns the `k` highest-frequency words stored under `prefix`, paired with their
+    /// `word_count`, so callers can rank autocomplete suggestions by popularity.
+    pub fn top_completions(&self, prefix: &str, k: usize) -> Vec<(String, u32)> {
+        let node = match self.walk(prefix.chars()) {
+            Some(node) => node,
+            None => return Vec::new(),
+        };
+
+        let mut counted = Vec::new();
+        let mut buffer = String::new();
+        Self::collect_counted(node, &mut buffer, &mut counted);
+        counted.sort_by(|(word_a, count_a), (word_b, count_b)| {
+            count_b.cmp(count_a).then_with(|| word_a.cmp(word_b))
+        });
+        counted.truncate(k);
+        counted
+            .into_iter()
+            .map(|(suffix, count)| (format!("{prefix}{suffix}"), count))
+            .collect()
+    }
+
+    /// Recursively accumulates `(suffix, word_count)` pairs from `node` downwards into
+    /// `counted`, using `buffer` to hold the path from `node` to the current child.
+    fn collect_counted(
+        node: &TrieNode<char, V>,
+        buffer: &mut String,
+        counted: &mut Vec<(String, u32)>,
+    ) {
+        if node.word_count > 0 {
+            counted.push((buffer.clone(), node.word_count));
+        }
+        for (char, child) in &node.children {
+            buffer.push(*char);
+            Self::collect_counted(child, buffer, counted);
+            buffer.pop();
+        }
+    }
+
+    /// Returns every stored word that is a prefix of `query`, sorted by increasing length.
+    ///
+    /// Walks `query` character by character through `children`, recording the consumed
+    /// prefix each time the current node carries a value, and stops as soon as a character
+    /// has no matching child.
+    pub fn find_prefixes(&self, query: &str) -> Vec<String> {
+        let mut node = &self.root;
+        let mut prefixes = Vec::new();
+        for (i, char) in query.char_indices() {
+            match node.children.get(&char) {
+                Some(next_node) => node = next_node,
+                None => break,
+            }
+            if node.word_count > 0 {
+                prefixes.push(query[..i + char.len_utf8()].to_string());
+            }
+        }
+        prefixes
+    }
+
+    /// Returns the longest stored word that is a prefix of `query`, if any.
+    pub fn find_longest_prefix(&self, query: &str) -> Option<String> {
+        self.find_prefixes(query).pop()
+    }
+
+    /// Searches for `pattern` in the `Trie`, where a `.` matches any single character.
+    ///
+    /// Returns `true` if some stored word matches `pattern` exactly.
+    pub fn search_pattern(&self, pattern: &str) -> bool {
+        Self::matches_from(&self.root, pattern)
+    }
+
+    /// Recursively matches `pattern` against the subtree rooted at `node`. A normal character
+    /// follows its matching child; a `.` branches into every child and succeeds if any branch
+    /// matches the remaining pattern.
+    fn matches_from(node: &TrieNode<char, V>, pattern: &str) -> bool {
+        let mut chars = pattern.chars();
+        let char = match chars.next() {
+            Some(char) => char,
+            None => return node.word_count > 0,
+        };
+        let rest = chars.as_str();
+
+        if char == '.' {
+            node.children
+                .values()
+                .any(|child| Self::matches_from(child, rest))
+        } else {
+            node.children
+                .get(&char)
+                .is_some_and(|child| Self::matches_from(child, rest))
+        }
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -91,9 +287,144 @@ mod tests {
     #[test]
     fn it_works() {
         let mut trie = Trie::new();
-        trie.insert("abcde");
-        assert_eq!(trie.search_prefix("abc"), true);
+        trie.insert("abcde".chars(), ());
+        assert_eq!(trie.search_prefix("abc".chars()), true);
+        assert_eq!(trie.get("abcde".chars()), Some(&()));
+        assert_eq!(trie.get("abc".chars()), None);
+    }
+
+    #[test]
+    fn string_api_back_compat_wrappers() {
+        let mut trie = Trie::new();
+        trie.insert_word("abcde");
         assert_eq!(trie.search_full_world("abcde"), true);
         assert_eq!(trie.search_full_world("abc"), false);
     }
+
+    #[test]
+    fn collects_words_under_prefix() {
+        let mut trie: Trie = Trie::new();
+        trie.insert("car".chars(), ());
+        trie.insert("card".chars(), ());
+        trie.insert("care".chars(), ());
+        trie.insert("cat".chars(), ());
+
+        let mut words = trie.collect_words("ca");
+        words.sort();
+        assert_eq!(words, vec!["car", "card", "care", "cat"]);
+
+        assert_eq!(trie.collect_words("xyz"), Vec::<String>::new());
+    }
+
+    #[test]
+    fn autocomplete_limits_results() {
+        let mut trie: Trie = Trie::new();
+        trie.insert("car".chars(), ());
+        trie.insert("card".chars(), ());
+        trie.insert("care".chars(), ());
+
+        assert_eq!(trie.autocomplete("ca", 2).len(), 2);
+        assert_eq!(trie.autocomplete("ca", 10).len(), 3);
+    }
+
+    #[test]
+    fn remove_prunes_dead_branches() {
+        let mut trie: Trie = Trie::new();
+        trie.insert("car".chars(), ());
+        trie.insert("card".chars(), ());
+
+        assert_eq!(trie.remove("card".chars()), true);
+        assert_eq!(trie.get("card".chars()), None);
+        assert_eq!(trie.get("car".chars()), Some(&()));
+
+        assert_eq!(trie.remove("car".chars()), true);
+        assert_eq!(trie.get("car".chars()), None);
+        assert_eq!(trie.search_prefix("c".chars()), false);
+
+        assert_eq!(trie.remove("car".chars()), false);
+    }
+
+    #[test]
+    fn keys_to_values() {
+        let mut trie: Trie<char, u32> = Trie::new();
+        trie.insert("one".chars(), 1);
+        trie.insert("two".chars(), 2);
+
+        assert_eq!(trie.get("one".chars()), Some(&1));
+        assert_eq!(trie.get("two".chars()), Some(&2));
+        assert_eq!(trie.get("three".chars()), None);
+    }
+
+    #[test]
+    fn byte_keyed_trie() {
+        let mut trie: Trie<u8, &str> = Trie::new();
+        trie.insert(b"get".iter().copied(), "GET handler");
+        trie.insert(b"get_user".iter().copied(), "GET /user handler");
+
+        assert_eq!(trie.get(b"get".iter().copied()), Some(&"GET handler"));
+        assert_eq!(
+            trie.get(b"get_user".iter().copied()),
+            Some(&"GET /user handler")
+        );
+        assert_eq!(trie.search_prefix(b"get_".iter().copied()), true);
+    }
+
+    #[test]
+    fn finds_prefixes_of_query() {
+        let mut trie: Trie = Trie::new();
+        trie.insert("a".chars(), ());
+        trie.insert("ab".chars(), ());
+        trie.insert("abc".chars(), ());
+        trie.insert("abd".chars(), ());
+
+        assert_eq!(trie.find_prefixes("abcd"), vec!["a", "ab", "abc"]);
+        assert_eq!(trie.find_longest_prefix("abcd"), Some("abc".to_string()));
+        assert_eq!(trie.find_prefixes("xyz"), Vec::<String>::new());
+        assert_eq!(trie.find_longest_prefix("xyz"), None);
+    }
+
+    #[test]
+    fn search_pattern_matches_wildcards() {
+        let mut trie: Trie = Trie::new();
+        trie.insert("bad".chars(), ());
+        trie.insert("bat".chars(), ());
+        trie.insert("cat".chars(), ());
+
+        assert_eq!(trie.search_pattern("b.d"), true);
+        assert_eq!(trie.search_pattern("b.."), true);
+        assert_eq!(trie.search_pattern("..."), true);
+        assert_eq!(trie.search_pattern("b.t"), true);
+        assert_eq!(trie.search_pattern("...."), false);
+        assert_eq!(trie.search_pattern("d.."), false);
+    }
+
+    #[test]
+    fn count_tracks_insert_frequency() {
+        let mut trie: Trie = Trie::new();
+        trie.insert("cat".chars(), ());
+        trie.insert("cat".chars(), ());
+        trie.insert("cat".chars(), ());
+        trie.insert("car".chars(), ());
+
+        assert_eq!(trie.count("cat".chars()), 3);
+        assert_eq!(trie.count("car".chars()), 1);
+        assert_eq!(trie.count("ca".chars()), 0);
+
+        assert_eq!(trie.search_full_world("cat"), true);
+        assert_eq!(trie.search_full_world("ca"), false);
+    }
+
+    #[test]
+    fn top_completions_ranks_by_frequency() {
+        let mut trie: Trie = Trie::new();
+        trie.insert("cat".chars(), ());
+        trie.insert("cat".chars(), ());
+        trie.insert("car".chars(), ());
+        trie.insert("car".chars(), ());
+        trie.insert("car".chars(), ());
+        trie.insert("cap".chars(), ());
+
+        let top = trie.top_completions("ca", 2);
+        assert_eq!(top, vec![("car".to_string(), 3), ("cat".to_string(), 2)]);
+    }
 }